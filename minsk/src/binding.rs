@@ -3,6 +3,7 @@ use crate::plumbing::Object;
 use crate::plumbing::ObjectKind;
 use crate::syntax::expressions::AssignmentExpressionSyntax;
 use crate::syntax::expressions::BinaryExpressionSyntax;
+use crate::syntax::expressions::CallExpressionSyntax;
 use crate::syntax::expressions::ExpressionSyntaxRef;
 use crate::syntax::expressions::LiteralExpressionSyntax;
 use crate::syntax::expressions::NameExpressionSyntax;
@@ -10,18 +11,27 @@ use crate::syntax::expressions::ParenthesizedExpressionSyntax;
 use crate::syntax::expressions::UnaryExpressionSyntax;
 use crate::syntax::statements::BlockStatementSyntax;
 use crate::syntax::statements::ExpressionStatementSyntax;
+use crate::syntax::statements::ForStatementSyntax;
+use crate::syntax::statements::FunctionDeclarationSyntax;
+use crate::syntax::statements::IfStatementSyntax;
+use crate::syntax::statements::ReturnStatementSyntax;
 use crate::syntax::statements::StatementSyntaxRef;
 use crate::syntax::statements::VariableDeclarationStatementSyntax;
+use crate::syntax::statements::WhileStatementSyntax;
 use crate::syntax::CompilationUnitSyntaxRef;
 use crate::syntax::SyntaxKind;
 use crate::syntax::SyntaxNodeRef;
+use crate::syntax::SyntaxToken;
+use crate::text::TextSpan;
 use crate::text::VariableSymbol;
 
+use self::lowering::Lowerer;
 use self::operators::BoundBinaryOperator;
 use self::operators::BoundUnaryOperator;
 use self::scope::BoundGlobalScope;
 use self::scope::BoundScope;
 
+pub(crate) mod lowering;
 mod operators;
 pub(crate) mod scope;
 
@@ -32,9 +42,19 @@ pub(crate) enum BoundNodeKind {
     VariableExpression,
     AssignmentExpression,
 
+    CallExpression,
+
     BlockStatement,
     ExpressionStatement,
     VariableDeclarationStatement,
+    IfStatement,
+    WhileStatement,
+    ForStatement,
+    GotoStatement,
+    ConditionalGotoStatement,
+    LabelStatement,
+    FunctionDeclaration,
+    ReturnStatement,
 }
 
 pub(crate) enum BoundNode {
@@ -56,6 +76,14 @@ pub(crate) enum BoundStatement {
     Block(BoundBlockStatement),
     Expression(BoundExpressionStatement),
     VariableDeclaration(BoundVariableDeclarationStatement),
+    If(BoundIfStatement),
+    While(BoundWhileStatement),
+    For(BoundForStatement),
+    Goto(BoundGotoStatement),
+    ConditionalGoto(BoundConditionalGotoStatement),
+    Label(BoundLabelStatement),
+    FunctionDeclaration(BoundFunctionDeclaration),
+    Return(BoundReturnStatement),
 }
 
 impl BoundStatement {
@@ -64,26 +92,122 @@ impl BoundStatement {
             BoundStatement::Block(_) => BoundNodeKind::BlockStatement,
             BoundStatement::Expression(_) => BoundNodeKind::ExpressionStatement,
             BoundStatement::VariableDeclaration(_) => BoundNodeKind::VariableDeclarationStatement,
+            BoundStatement::If(_) => BoundNodeKind::IfStatement,
+            BoundStatement::While(_) => BoundNodeKind::WhileStatement,
+            BoundStatement::For(_) => BoundNodeKind::ForStatement,
+            BoundStatement::Goto(_) => BoundNodeKind::GotoStatement,
+            BoundStatement::ConditionalGoto(_) => BoundNodeKind::ConditionalGotoStatement,
+            BoundStatement::Label(_) => BoundNodeKind::LabelStatement,
+            BoundStatement::FunctionDeclaration(_) => BoundNodeKind::FunctionDeclaration,
+            BoundStatement::Return(_) => BoundNodeKind::ReturnStatement,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        match self {
+            BoundStatement::Block(s) => s.span,
+            BoundStatement::Expression(s) => s.span,
+            BoundStatement::VariableDeclaration(s) => s.span,
+            BoundStatement::If(s) => s.span,
+            BoundStatement::While(s) => s.span,
+            BoundStatement::For(s) => s.span,
+            BoundStatement::Goto(s) => s.span,
+            BoundStatement::ConditionalGoto(s) => s.span,
+            BoundStatement::Label(s) => s.span,
+            BoundStatement::FunctionDeclaration(s) => s.span,
+            BoundStatement::Return(s) => s.span,
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundBlockStatement {
+    pub(crate) span: TextSpan,
     pub(crate) statements: Vec<BoundStatement>,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundExpressionStatement {
+    pub(crate) span: TextSpan,
     pub(crate) expression: BoundExpression,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundVariableDeclarationStatement {
+    pub(crate) span: TextSpan,
     pub(crate) variable: VariableSymbol,
     pub(crate) initializer: BoundExpression,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct BoundIfStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) condition: Box<BoundExpression>,
+    pub(crate) then_statement: Box<BoundStatement>,
+    pub(crate) else_statement: Option<Box<BoundStatement>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundWhileStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) condition: Box<BoundExpression>,
+    pub(crate) body: Box<BoundStatement>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundForStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) variable: VariableSymbol,
+    pub(crate) lower_bound: Box<BoundExpression>,
+    pub(crate) upper_bound: Box<BoundExpression>,
+    pub(crate) body: Box<BoundStatement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct BoundLabel {
+    pub(crate) name: std::rc::Rc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundGotoStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) label: BoundLabel,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundConditionalGotoStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) label: BoundLabel,
+    pub(crate) condition: Box<BoundExpression>,
+    pub(crate) jump_if_true: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundLabelStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) label: BoundLabel,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FunctionSymbol {
+    pub(crate) name: String,
+    pub(crate) parameters: Vec<VariableSymbol>,
+    pub(crate) return_type: ObjectKind,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundFunctionDeclaration {
+    pub(crate) span: TextSpan,
+    pub(crate) function: FunctionSymbol,
+    pub(crate) body: Box<BoundStatement>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundReturnStatement {
+    pub(crate) span: TextSpan,
+    pub(crate) expression: Option<Box<BoundExpression>>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum BoundExpression {
     Binary(BoundBinaryExpression),
@@ -91,6 +215,7 @@ pub(crate) enum BoundExpression {
     Literal(BoundLiteralExpression),
     Variable(BoundVariableExpression),
     Assignment(BoundAssignmentExpression),
+    Call(BoundCallExpression),
 }
 
 impl BoundExpression {
@@ -101,6 +226,7 @@ impl BoundExpression {
             BoundExpression::Literal(_) => BoundNodeKind::LiteralExpression,
             BoundExpression::Variable(_) => BoundNodeKind::VariableExpression,
             BoundExpression::Assignment(_) => BoundNodeKind::AssignmentExpression,
+            BoundExpression::Call(_) => BoundNodeKind::CallExpression,
         }
     }
 
@@ -111,6 +237,18 @@ impl BoundExpression {
             BoundExpression::Literal(e) => e.value.kind(),
             BoundExpression::Variable(e) => e.variable.kind,
             BoundExpression::Assignment(e) => e.expression.get_type(),
+            BoundExpression::Call(e) => e.function.return_type,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        match self {
+            BoundExpression::Binary(e) => e.span,
+            BoundExpression::Unary(e) => e.span,
+            BoundExpression::Literal(e) => e.span,
+            BoundExpression::Variable(e) => e.span,
+            BoundExpression::Assignment(e) => e.span,
+            BoundExpression::Call(e) => e.span,
         }
     }
 }
@@ -125,10 +263,12 @@ pub(crate) enum BoundBinaryOperatorKind {
     LogicalOr,
     Equality,
     Inequality,
+    LessOrEquals,
 }
 
 #[derive(Debug, Clone)]
 pub struct BoundBinaryExpression {
+    pub(crate) span: TextSpan,
     pub(crate) left: Box<BoundExpression>,
     pub(crate) operator: &'static BoundBinaryOperator,
     pub(crate) right: Box<BoundExpression>,
@@ -143,63 +283,104 @@ pub(crate) enum BoundUnaryOperatorKind {
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundUnaryExpression {
+    pub(crate) span: TextSpan,
     pub(crate) operator: &'static BoundUnaryOperator,
     pub(crate) operand: Box<BoundExpression>,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundLiteralExpression {
+    pub(crate) span: TextSpan,
     pub(crate) value: Object,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundVariableExpression {
+    pub(crate) span: TextSpan,
     pub(crate) variable: VariableSymbol,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct BoundAssignmentExpression {
+    pub(crate) span: TextSpan,
     pub(crate) variable: VariableSymbol,
     pub(crate) expression: Box<BoundExpression>,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct BoundCallExpression {
+    pub(crate) span: TextSpan,
+    pub(crate) function: FunctionSymbol,
+    pub(crate) arguments: Vec<BoundExpression>,
+}
+
 pub(crate) struct Binder {
     pub(crate) diagnostics: DiagnosticBag,
     scope: BoundScope,
+    function: Option<FunctionSymbol>,
 }
 
 impl Binder {
     pub(crate) fn bind_statement(&mut self, statement: StatementSyntaxRef) -> Box<BoundStatement> {
+        let span = SyntaxNodeRef::Statement(statement).span();
         match statement {
-            StatementSyntaxRef::Block(s) => self.bind_block_statement(s),
-            StatementSyntaxRef::Expression(s) => self.bind_expression_statement(s),
+            StatementSyntaxRef::Block(s) => self.bind_block_statement(s, span),
+            StatementSyntaxRef::Expression(s) => self.bind_expression_statement(s, span),
             StatementSyntaxRef::VariableDeclaration(s) => {
-                self.bind_variable_declaration_statement(s)
+                self.bind_variable_declaration_statement(s, span)
             }
+            StatementSyntaxRef::If(s) => self.bind_if_statement(s, span),
+            StatementSyntaxRef::While(s) => self.bind_while_statement(s, span),
+            StatementSyntaxRef::For(s) => self.bind_for_statement(s, span),
+            StatementSyntaxRef::FunctionDeclaration(s) => self.bind_function_declaration(s, span),
+            StatementSyntaxRef::Return(s) => self.bind_return_statement(s, span),
         }
     }
 
+    fn bind_expression_with_target_type(
+        &mut self,
+        expression: ExpressionSyntaxRef,
+        target_type: ObjectKind,
+    ) -> Box<BoundExpression> {
+        let result = self.bind_expression(expression);
+        if result.get_type() != target_type {
+            self.diagnostics.report_cannot_convert(
+                SyntaxNodeRef::Expression(expression).span(),
+                result.get_type(),
+                target_type,
+            );
+        }
+        result
+    }
+
     pub(crate) fn bind_expression(
         &mut self,
         expression: ExpressionSyntaxRef,
     ) -> Box<BoundExpression> {
+        let span = SyntaxNodeRef::Expression(expression).span();
         match expression {
-            ExpressionSyntaxRef::Binary(e) => self.bind_binary_expression(e),
-            ExpressionSyntaxRef::Unary(e) => self.bind_unary_expression(e),
-            ExpressionSyntaxRef::Literal(e) => self.bind_literal_expression(e),
+            ExpressionSyntaxRef::Binary(e) => self.bind_binary_expression(e, span),
+            ExpressionSyntaxRef::Unary(e) => self.bind_unary_expression(e, span),
+            ExpressionSyntaxRef::Literal(e) => self.bind_literal_expression(e, span),
             ExpressionSyntaxRef::Parenthesized(e) => self.bind_parenthesized_expression(e),
-            ExpressionSyntaxRef::Name(e) => self.bind_name_expression(e),
-            ExpressionSyntaxRef::Assignment(e) => self.bind_assignment_expression(e),
+            ExpressionSyntaxRef::Name(e) => self.bind_name_expression(e, span),
+            ExpressionSyntaxRef::Assignment(e) => self.bind_assignment_expression(e, span),
+            ExpressionSyntaxRef::Call(e) => self.bind_call_expression(e, span),
         }
     }
 
-    fn bind_binary_expression(&mut self, e: &BinaryExpressionSyntax) -> Box<BoundExpression> {
+    fn bind_binary_expression(
+        &mut self,
+        e: &BinaryExpressionSyntax,
+        span: TextSpan,
+    ) -> Box<BoundExpression> {
         let left = self.bind_expression(e.left.create_ref());
         let right = self.bind_expression(e.right.create_ref());
         let operator =
             BoundBinaryOperator::bind(e.operator_token.kind, left.get_type(), right.get_type());
         if let Some(operator) = operator {
             Box::new(BoundExpression::Binary(BoundBinaryExpression {
+                span,
                 left,
                 operator,
                 right,
@@ -215,11 +396,16 @@ impl Binder {
         }
     }
 
-    fn bind_unary_expression(&mut self, e: &UnaryExpressionSyntax) -> Box<BoundExpression> {
+    fn bind_unary_expression(
+        &mut self,
+        e: &UnaryExpressionSyntax,
+        span: TextSpan,
+    ) -> Box<BoundExpression> {
         let operand = self.bind_expression(e.operand.create_ref());
         let operator = BoundUnaryOperator::bind(e.operator_token.kind, operand.get_type());
         if let Some(operator) = operator {
             Box::new(BoundExpression::Unary(BoundUnaryExpression {
+                span,
                 operator,
                 operand,
             }))
@@ -233,8 +419,13 @@ impl Binder {
         }
     }
 
-    fn bind_literal_expression(&self, e: &LiteralExpressionSyntax) -> Box<BoundExpression> {
+    fn bind_literal_expression(
+        &self,
+        e: &LiteralExpressionSyntax,
+        span: TextSpan,
+    ) -> Box<BoundExpression> {
         Box::new(BoundExpression::Literal(BoundLiteralExpression {
+            span,
             value: e.value.clone(),
         }))
     }
@@ -250,22 +441,29 @@ impl Binder {
         Self {
             diagnostics: DiagnosticBag::new(),
             scope,
+            function: None,
         }
     }
 
-    fn bind_name_expression(&mut self, e: &NameExpressionSyntax) -> Box<BoundExpression> {
+    fn bind_name_expression(
+        &mut self,
+        e: &NameExpressionSyntax,
+        span: TextSpan,
+    ) -> Box<BoundExpression> {
         let name = e.identifier_token.text.clone();
 
         let variable = self.scope.try_lookup(&name);
 
         match variable {
             Some(v) => Box::new(BoundExpression::Variable(BoundVariableExpression {
+                span,
                 variable: v.clone(),
             })),
             None => {
                 self.diagnostics
                     .report_undefined_name(e.identifier_token.span(), &name);
                 Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                    span,
                     value: Object::Number(0),
                 }))
             }
@@ -275,6 +473,7 @@ impl Binder {
     fn bind_assignment_expression(
         &mut self,
         e: &AssignmentExpressionSyntax,
+        span: TextSpan,
     ) -> Box<BoundExpression> {
         let name = e.identifier_token.text.clone();
         let expression = self.bind_expression(e.expression.create_ref());
@@ -302,30 +501,87 @@ impl Binder {
         }
 
         Box::new(BoundExpression::Assignment(BoundAssignmentExpression {
+            span,
             variable,
             expression,
         }))
     }
 
+    fn bind_call_expression(
+        &mut self,
+        e: &CallExpressionSyntax,
+        span: TextSpan,
+    ) -> Box<BoundExpression> {
+        let name = e.identifier_token.text.clone();
+
+        let function = match self.scope.try_lookup_function(&name) {
+            Some(function) => function.clone(),
+            None => {
+                self.diagnostics
+                    .report_undefined_name(e.identifier_token.span(), &name);
+                return Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                    span,
+                    value: Object::Number(0),
+                }));
+            }
+        };
+
+        if e.arguments.len() != function.parameters.len() {
+            self.diagnostics.report_wrong_argument_count(
+                SyntaxNodeRef::Token(&e.close_parenthesis_token).span(),
+                &name,
+                function.parameters.len(),
+                e.arguments.len(),
+            );
+            return Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                span,
+                value: Object::Number(0),
+            }));
+        }
+
+        let arguments = e
+            .arguments
+            .iter()
+            .zip(&function.parameters)
+            .map(|(argument, parameter)| {
+                *self.bind_expression_with_target_type(argument.create_ref(), parameter.kind)
+            })
+            .collect();
+
+        Box::new(BoundExpression::Call(BoundCallExpression {
+            span,
+            function,
+            arguments,
+        }))
+    }
+
     pub(crate) fn bind_global_scope(
-        previous: Option<&BoundGlobalScope>,
+        previous: Option<BoundGlobalScope>,
         syntax: CompilationUnitSyntaxRef,
     ) -> BoundGlobalScope {
-        let parent_scope = Self::create_parent_scopes(previous);
-        let mut binder = Binder::new(parent_scope);
+        let parent_scope = Self::create_parent_scopes(previous.as_ref());
+        let mut binder = Binder::new(BoundScope::new(Some(Box::new(parent_scope))));
         let statement = binder.bind_statement(syntax.statement);
+        let statement = Lowerer::lower(*statement);
         let variables = binder
             .scope
             .get_declared_variables()
             .into_iter()
             .cloned()
             .collect();
+        let functions = binder
+            .scope
+            .get_declared_functions()
+            .into_iter()
+            .cloned()
+            .collect();
         let diagnostics = binder.diagnostics.into_iter().collect::<Vec<_>>();
         BoundGlobalScope {
-            previous: None,
+            previous: previous.map(Box::new),
             diagnostics,
             variables,
-            statement: *statement,
+            functions,
+            statement,
         }
     }
 
@@ -342,12 +598,19 @@ impl Binder {
             for v in &global.variables {
                 scope.try_declare(v.clone());
             }
+            for f in &global.functions {
+                scope.try_declare_function(f.clone());
+            }
             parent = scope;
         }
         parent
     }
 
-    fn bind_block_statement(&mut self, s: &BlockStatementSyntax) -> Box<BoundStatement> {
+    fn bind_block_statement(
+        &mut self,
+        s: &BlockStatementSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
         let mut statements = Vec::new();
 
         let mut scope = BoundScope::new(None);
@@ -363,12 +626,20 @@ impl Binder {
         std::mem::swap(&mut scope, self.scope.parent.as_mut().unwrap());
         self.scope = scope;
 
-        Box::new(BoundStatement::Block(BoundBlockStatement { statements }))
+        Box::new(BoundStatement::Block(BoundBlockStatement {
+            span,
+            statements,
+        }))
     }
 
-    fn bind_expression_statement(&mut self, s: &ExpressionStatementSyntax) -> Box<BoundStatement> {
+    fn bind_expression_statement(
+        &mut self,
+        s: &ExpressionStatementSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
         let expression = self.bind_expression(s.expression.create_ref());
         Box::new(BoundStatement::Expression(BoundExpressionStatement {
+            span,
             expression: *expression,
         }))
     }
@@ -376,14 +647,27 @@ impl Binder {
     fn bind_variable_declaration_statement(
         &mut self,
         s: &VariableDeclarationStatementSyntax,
+        span: TextSpan,
     ) -> Box<BoundStatement> {
         let name = s.identifier.text.clone();
-        let initializer = self.bind_expression(s.initializer.create_ref());
         let is_read_only = s.keyword.kind == SyntaxKind::LetKeyword;
+
+        let declared_type = s
+            .type_clause
+            .as_ref()
+            .map(|type_clause| self.bind_type_identifier(&type_clause.identifier));
+
+        let initializer = match declared_type {
+            Some(declared_type) => {
+                self.bind_expression_with_target_type(s.initializer.create_ref(), declared_type)
+            }
+            None => self.bind_expression(s.initializer.create_ref()),
+        };
+
         let variable = VariableSymbol {
             name: name.clone(),
             is_read_only,
-            kind: initializer.get_type(),
+            kind: declared_type.unwrap_or_else(|| initializer.get_type()),
         };
         if !self.scope.try_declare(variable.clone()) {
             self.diagnostics
@@ -391,9 +675,180 @@ impl Binder {
         }
         Box::new(BoundStatement::VariableDeclaration(
             BoundVariableDeclarationStatement {
+                span,
                 variable,
                 initializer: *initializer,
             },
         ))
     }
+
+    fn bind_if_statement(&mut self, s: &IfStatementSyntax, span: TextSpan) -> Box<BoundStatement> {
+        let condition =
+            self.bind_expression_with_target_type(s.condition.create_ref(), ObjectKind::Boolean);
+        let then_statement = self.bind_statement(s.then_statement.create_ref());
+        let else_statement = s
+            .else_clause
+            .as_ref()
+            .map(|clause| self.bind_statement(clause.else_statement.create_ref()));
+        Box::new(BoundStatement::If(BoundIfStatement {
+            span,
+            condition,
+            then_statement,
+            else_statement,
+        }))
+    }
+
+    fn bind_while_statement(
+        &mut self,
+        s: &WhileStatementSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
+        let condition =
+            self.bind_expression_with_target_type(s.condition.create_ref(), ObjectKind::Boolean);
+        let body = self.bind_statement(s.body.create_ref());
+        Box::new(BoundStatement::While(BoundWhileStatement {
+            span,
+            condition,
+            body,
+        }))
+    }
+
+    fn bind_for_statement(
+        &mut self,
+        s: &ForStatementSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
+        let lower_bound =
+            self.bind_expression_with_target_type(s.lower_bound.create_ref(), ObjectKind::Number);
+        let upper_bound =
+            self.bind_expression_with_target_type(s.upper_bound.create_ref(), ObjectKind::Number);
+
+        let mut scope = BoundScope::new(None);
+        std::mem::swap(&mut scope, &mut self.scope);
+        self.scope = BoundScope::new(Some(Box::new(scope)));
+
+        let variable = VariableSymbol {
+            name: s.identifier.text.clone(),
+            is_read_only: true,
+            kind: ObjectKind::Number,
+        };
+        self.scope.try_declare(variable.clone());
+
+        let body = self.bind_statement(s.body.create_ref());
+
+        let mut scope = BoundScope::new(None);
+        std::mem::swap(&mut scope, self.scope.parent.as_mut().unwrap());
+        self.scope = scope;
+
+        Box::new(BoundStatement::For(BoundForStatement {
+            span,
+            variable,
+            lower_bound,
+            upper_bound,
+            body,
+        }))
+    }
+
+    fn bind_type_identifier(&mut self, identifier: &SyntaxToken) -> ObjectKind {
+        match identifier.text.as_str() {
+            "int" => ObjectKind::Number,
+            "bool" => ObjectKind::Boolean,
+            _ => {
+                self.diagnostics
+                    .report_undefined_type(identifier.span(), &identifier.text);
+                ObjectKind::Number
+            }
+        }
+    }
+
+    fn bind_function_declaration(
+        &mut self,
+        s: &FunctionDeclarationSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
+        let parameters = s
+            .parameters
+            .iter()
+            .map(|p| VariableSymbol {
+                name: p.identifier.text.clone(),
+                is_read_only: true,
+                kind: self.bind_type_identifier(&p.type_clause.identifier),
+            })
+            .collect::<Vec<_>>();
+
+        let return_type = s
+            .type_clause
+            .as_ref()
+            .map(|t| self.bind_type_identifier(&t.identifier))
+            .unwrap_or(ObjectKind::Void);
+
+        let function = FunctionSymbol {
+            name: s.identifier.text.clone(),
+            parameters: parameters.clone(),
+            return_type,
+        };
+        if !self.scope.try_declare_function(function.clone()) {
+            self.diagnostics
+                .report_function_already_declared(s.identifier.span(), &function.name);
+        }
+
+        let mut scope = BoundScope::new(None);
+        std::mem::swap(&mut scope, &mut self.scope);
+        self.scope = BoundScope::new(Some(Box::new(scope)));
+        for parameter in &parameters {
+            self.scope.try_declare(parameter.clone());
+        }
+
+        let previous_function = self.function.replace(function.clone());
+        let body_span = SyntaxNodeRef::Statement(StatementSyntaxRef::Block(&s.body)).span();
+        let body = self.bind_block_statement(&s.body, body_span);
+        self.function = previous_function;
+
+        let mut scope = BoundScope::new(None);
+        std::mem::swap(&mut scope, self.scope.parent.as_mut().unwrap());
+        self.scope = scope;
+
+        Box::new(BoundStatement::FunctionDeclaration(
+            BoundFunctionDeclaration {
+                span,
+                function,
+                body,
+            },
+        ))
+    }
+
+    fn bind_return_statement(
+        &mut self,
+        s: &ReturnStatementSyntax,
+        span: TextSpan,
+    ) -> Box<BoundStatement> {
+        let return_type = self
+            .function
+            .as_ref()
+            .map_or(ObjectKind::Void, |f| f.return_type);
+
+        let expression = match &s.expression {
+            Some(expression_syntax) if return_type == ObjectKind::Void => {
+                self.diagnostics.report_invalid_return_expression(
+                    SyntaxNodeRef::Expression(expression_syntax.create_ref()).span(),
+                );
+                None
+            }
+            Some(expression_syntax) => Some(self.bind_expression_with_target_type(
+                expression_syntax.create_ref(),
+                return_type,
+            )),
+            None if return_type != ObjectKind::Void => {
+                self.diagnostics
+                    .report_missing_return_expression(s.return_keyword.span(), return_type);
+                None
+            }
+            None => None,
+        };
+
+        Box::new(BoundStatement::Return(BoundReturnStatement {
+            span,
+            expression,
+        }))
+    }
 }
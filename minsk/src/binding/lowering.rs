@@ -0,0 +1,455 @@
+use crate::plumbing::Object;
+use crate::plumbing::ObjectKind;
+use crate::syntax::SyntaxKind;
+use crate::text::VariableSymbol;
+
+use super::operators::BoundBinaryOperator;
+use super::BoundAssignmentExpression;
+use super::BoundBinaryExpression;
+use super::BoundBinaryOperatorKind;
+use super::BoundBlockStatement;
+use super::BoundCallExpression;
+use super::BoundConditionalGotoStatement;
+use super::BoundExpression;
+use super::BoundExpressionStatement;
+use super::BoundForStatement;
+use super::BoundFunctionDeclaration;
+use super::BoundGotoStatement;
+use super::BoundIfStatement;
+use super::BoundLabel;
+use super::BoundLabelStatement;
+use super::BoundLiteralExpression;
+use super::BoundReturnStatement;
+use super::BoundStatement;
+use super::BoundUnaryExpression;
+use super::BoundUnaryOperatorKind;
+use super::BoundVariableDeclarationStatement;
+use super::BoundVariableExpression;
+use super::BoundWhileStatement;
+
+/// Walks a bound tree bottom-up, rewriting every node from its
+/// (possibly rewritten) children.
+pub(crate) trait BoundTreeRewriter {
+    fn rewrite_statement(&mut self, statement: BoundStatement) -> BoundStatement {
+        match statement {
+            BoundStatement::Block(s) => self.rewrite_block_statement(s),
+            BoundStatement::Expression(s) => self.rewrite_expression_statement(s),
+            BoundStatement::VariableDeclaration(s) => {
+                self.rewrite_variable_declaration_statement(s)
+            }
+            BoundStatement::If(s) => self.rewrite_if_statement(s),
+            BoundStatement::While(s) => self.rewrite_while_statement(s),
+            BoundStatement::For(s) => self.rewrite_for_statement(s),
+            BoundStatement::Goto(s) => BoundStatement::Goto(s),
+            BoundStatement::ConditionalGoto(s) => self.rewrite_conditional_goto_statement(s),
+            BoundStatement::Label(s) => BoundStatement::Label(s),
+            BoundStatement::FunctionDeclaration(s) => self.rewrite_function_declaration(s),
+            BoundStatement::Return(s) => self.rewrite_return_statement(s),
+        }
+    }
+
+    fn rewrite_block_statement(&mut self, s: BoundBlockStatement) -> BoundStatement {
+        let statements = s
+            .statements
+            .into_iter()
+            .map(|s| self.rewrite_statement(s))
+            .collect();
+        BoundStatement::Block(BoundBlockStatement {
+            span: s.span,
+            statements,
+        })
+    }
+
+    fn rewrite_expression_statement(&mut self, s: BoundExpressionStatement) -> BoundStatement {
+        let span = s.span;
+        let expression = *self.rewrite_expression(Box::new(s.expression));
+        BoundStatement::Expression(BoundExpressionStatement { span, expression })
+    }
+
+    fn rewrite_variable_declaration_statement(
+        &mut self,
+        s: BoundVariableDeclarationStatement,
+    ) -> BoundStatement {
+        let span = s.span;
+        let initializer = *self.rewrite_expression(Box::new(s.initializer));
+        BoundStatement::VariableDeclaration(BoundVariableDeclarationStatement {
+            span,
+            variable: s.variable,
+            initializer,
+        })
+    }
+
+    fn rewrite_if_statement(&mut self, s: BoundIfStatement) -> BoundStatement {
+        let span = s.span;
+        let condition = self.rewrite_expression(s.condition);
+        let then_statement = Box::new(self.rewrite_statement(*s.then_statement));
+        let else_statement = s
+            .else_statement
+            .map(|e| Box::new(self.rewrite_statement(*e)));
+        BoundStatement::If(BoundIfStatement {
+            span,
+            condition,
+            then_statement,
+            else_statement,
+        })
+    }
+
+    fn rewrite_while_statement(&mut self, s: BoundWhileStatement) -> BoundStatement {
+        let span = s.span;
+        let condition = self.rewrite_expression(s.condition);
+        let body = Box::new(self.rewrite_statement(*s.body));
+        BoundStatement::While(BoundWhileStatement {
+            span,
+            condition,
+            body,
+        })
+    }
+
+    fn rewrite_for_statement(&mut self, s: BoundForStatement) -> BoundStatement {
+        let span = s.span;
+        let lower_bound = self.rewrite_expression(s.lower_bound);
+        let upper_bound = self.rewrite_expression(s.upper_bound);
+        let body = Box::new(self.rewrite_statement(*s.body));
+        BoundStatement::For(BoundForStatement {
+            span,
+            variable: s.variable,
+            lower_bound,
+            upper_bound,
+            body,
+        })
+    }
+
+    fn rewrite_conditional_goto_statement(
+        &mut self,
+        s: BoundConditionalGotoStatement,
+    ) -> BoundStatement {
+        let span = s.span;
+        let condition = self.rewrite_expression(s.condition);
+        BoundStatement::ConditionalGoto(BoundConditionalGotoStatement {
+            span,
+            label: s.label,
+            condition,
+            jump_if_true: s.jump_if_true,
+        })
+    }
+
+    fn rewrite_function_declaration(&mut self, s: BoundFunctionDeclaration) -> BoundStatement {
+        let span = s.span;
+        let body = Box::new(self.rewrite_statement(*s.body));
+        BoundStatement::FunctionDeclaration(BoundFunctionDeclaration {
+            span,
+            function: s.function,
+            body,
+        })
+    }
+
+    fn rewrite_return_statement(&mut self, s: BoundReturnStatement) -> BoundStatement {
+        let span = s.span;
+        let expression = s.expression.map(|e| self.rewrite_expression(e));
+        BoundStatement::Return(BoundReturnStatement { span, expression })
+    }
+
+    fn rewrite_expression(&mut self, expression: Box<BoundExpression>) -> Box<BoundExpression> {
+        match *expression {
+            BoundExpression::Binary(e) => self.rewrite_binary_expression(e),
+            BoundExpression::Unary(e) => self.rewrite_unary_expression(e),
+            BoundExpression::Literal(e) => Box::new(BoundExpression::Literal(e)),
+            BoundExpression::Variable(e) => Box::new(BoundExpression::Variable(e)),
+            BoundExpression::Assignment(e) => self.rewrite_assignment_expression(e),
+            BoundExpression::Call(e) => self.rewrite_call_expression(e),
+        }
+    }
+
+    fn rewrite_binary_expression(&mut self, e: BoundBinaryExpression) -> Box<BoundExpression> {
+        let span = e.span;
+        let left = self.rewrite_expression(e.left);
+        let right = self.rewrite_expression(e.right);
+        Box::new(BoundExpression::Binary(BoundBinaryExpression {
+            span,
+            left,
+            operator: e.operator,
+            right,
+        }))
+    }
+
+    fn rewrite_unary_expression(&mut self, e: BoundUnaryExpression) -> Box<BoundExpression> {
+        let span = e.span;
+        let operand = self.rewrite_expression(e.operand);
+        Box::new(BoundExpression::Unary(BoundUnaryExpression {
+            span,
+            operator: e.operator,
+            operand,
+        }))
+    }
+
+    fn rewrite_assignment_expression(
+        &mut self,
+        e: BoundAssignmentExpression,
+    ) -> Box<BoundExpression> {
+        let span = e.span;
+        let expression = self.rewrite_expression(e.expression);
+        Box::new(BoundExpression::Assignment(BoundAssignmentExpression {
+            span,
+            variable: e.variable,
+            expression,
+        }))
+    }
+
+    fn rewrite_call_expression(&mut self, e: BoundCallExpression) -> Box<BoundExpression> {
+        let span = e.span;
+        let arguments = e
+            .arguments
+            .into_iter()
+            .map(|a| *self.rewrite_expression(Box::new(a)))
+            .collect();
+        Box::new(BoundExpression::Call(BoundCallExpression {
+            span,
+            function: e.function,
+            arguments,
+        }))
+    }
+}
+
+/// Folds constants and desugars `while`/`for` into labels and gotos.
+/// `if` statements, blocks, function declarations and returns are kept
+/// as structured nodes; the lowered loops are nested blocks, not flattened.
+pub(crate) struct Lowerer {
+    label_count: usize,
+    variable_count: usize,
+}
+
+impl Lowerer {
+    fn new() -> Self {
+        Self {
+            label_count: 0,
+            variable_count: 0,
+        }
+    }
+
+    fn generate_label(&mut self) -> BoundLabel {
+        self.label_count += 1;
+        BoundLabel {
+            name: format!("Label{}", self.label_count).into(),
+        }
+    }
+
+    fn generate_variable(&mut self, prefix: &str, kind: ObjectKind) -> VariableSymbol {
+        self.variable_count += 1;
+        VariableSymbol {
+            name: format!("{prefix}{}", self.variable_count),
+            is_read_only: true,
+            kind,
+        }
+    }
+
+    pub(crate) fn lower(statement: BoundStatement) -> BoundStatement {
+        let mut lowerer = Lowerer::new();
+        lowerer.rewrite_statement(statement)
+    }
+}
+
+impl BoundTreeRewriter for Lowerer {
+    fn rewrite_while_statement(&mut self, s: BoundWhileStatement) -> BoundStatement {
+        let span = s.span;
+        let continue_label = self.generate_label();
+        let check_label = self.generate_label();
+
+        let goto_check = BoundStatement::Goto(BoundGotoStatement {
+            span,
+            label: check_label.clone(),
+        });
+        let continue_label_statement = BoundStatement::Label(BoundLabelStatement {
+            span,
+            label: continue_label.clone(),
+        });
+        let body = self.rewrite_statement(*s.body);
+        let check_label_statement = BoundStatement::Label(BoundLabelStatement {
+            span,
+            label: check_label,
+        });
+        let condition = self.rewrite_expression(s.condition);
+        let goto_true = BoundStatement::ConditionalGoto(BoundConditionalGotoStatement {
+            span,
+            label: continue_label,
+            condition,
+            jump_if_true: true,
+        });
+
+        BoundStatement::Block(BoundBlockStatement {
+            span,
+            statements: vec![
+                goto_check,
+                continue_label_statement,
+                body,
+                check_label_statement,
+                goto_true,
+            ],
+        })
+    }
+
+    fn rewrite_for_statement(&mut self, s: BoundForStatement) -> BoundStatement {
+        let span = s.span;
+        let variable_declaration =
+            BoundStatement::VariableDeclaration(BoundVariableDeclarationStatement {
+                span,
+                variable: s.variable.clone(),
+                initializer: *s.lower_bound,
+            });
+
+        let upper_bound_variable = self.generate_variable("upperBound", ObjectKind::Number);
+        let upper_bound_declaration =
+            BoundStatement::VariableDeclaration(BoundVariableDeclarationStatement {
+                span,
+                variable: upper_bound_variable.clone(),
+                initializer: *s.upper_bound,
+            });
+
+        let condition = Box::new(BoundExpression::Binary(BoundBinaryExpression {
+            span,
+            left: Box::new(BoundExpression::Variable(BoundVariableExpression {
+                span,
+                variable: s.variable.clone(),
+            })),
+            operator: BoundBinaryOperator::bind(
+                SyntaxKind::LessThanOrEqualsToken,
+                ObjectKind::Number,
+                ObjectKind::Number,
+            )
+            .expect("`<=` must be defined for numbers"),
+            right: Box::new(BoundExpression::Variable(BoundVariableExpression {
+                span,
+                variable: upper_bound_variable,
+            })),
+        }));
+
+        let increment = BoundStatement::Expression(BoundExpressionStatement {
+            span,
+            expression: BoundExpression::Assignment(BoundAssignmentExpression {
+                span,
+                variable: s.variable.clone(),
+                expression: Box::new(BoundExpression::Binary(BoundBinaryExpression {
+                    span,
+                    left: Box::new(BoundExpression::Variable(BoundVariableExpression {
+                        span,
+                        variable: s.variable,
+                    })),
+                    operator: BoundBinaryOperator::bind(
+                        SyntaxKind::PlusToken,
+                        ObjectKind::Number,
+                        ObjectKind::Number,
+                    )
+                    .expect("`+` must be defined for numbers"),
+                    right: Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                        span,
+                        value: Object::Number(1),
+                    })),
+                })),
+            }),
+        });
+
+        let while_body = BoundStatement::Block(BoundBlockStatement {
+            span,
+            statements: vec![*s.body, increment],
+        });
+        let while_statement = BoundStatement::While(BoundWhileStatement {
+            span,
+            condition,
+            body: Box::new(while_body),
+        });
+
+        self.rewrite_statement(BoundStatement::Block(BoundBlockStatement {
+            span,
+            statements: vec![variable_declaration, upper_bound_declaration, while_statement],
+        }))
+    }
+
+    fn rewrite_binary_expression(&mut self, e: BoundBinaryExpression) -> Box<BoundExpression> {
+        let span = e.span;
+        let left = self.rewrite_expression(e.left);
+        let right = self.rewrite_expression(e.right);
+        if let (BoundExpression::Literal(l), BoundExpression::Literal(r)) =
+            (left.as_ref(), right.as_ref())
+        {
+            if let Some(value) = fold_binary(e.operator.kind, &l.value, &r.value) {
+                return Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                    span,
+                    value,
+                }));
+            }
+        }
+        Box::new(BoundExpression::Binary(BoundBinaryExpression {
+            span,
+            left,
+            operator: e.operator,
+            right,
+        }))
+    }
+
+    fn rewrite_unary_expression(&mut self, e: BoundUnaryExpression) -> Box<BoundExpression> {
+        let span = e.span;
+        let operand = self.rewrite_expression(e.operand);
+        if let BoundExpression::Literal(l) = operand.as_ref() {
+            if let Some(value) = fold_unary(e.operator.kind, &l.value) {
+                return Box::new(BoundExpression::Literal(BoundLiteralExpression {
+                    span,
+                    value,
+                }));
+            }
+        }
+        Box::new(BoundExpression::Unary(BoundUnaryExpression {
+            span,
+            operator: e.operator,
+            operand,
+        }))
+    }
+}
+
+fn fold_binary(kind: BoundBinaryOperatorKind, left: &Object, right: &Object) -> Option<Object> {
+    match (kind, left, right) {
+        (BoundBinaryOperatorKind::Addition, Object::Number(l), Object::Number(r)) => {
+            l.checked_add(*r).map(Object::Number)
+        }
+        (BoundBinaryOperatorKind::Subtraction, Object::Number(l), Object::Number(r)) => {
+            l.checked_sub(*r).map(Object::Number)
+        }
+        (BoundBinaryOperatorKind::Multiplication, Object::Number(l), Object::Number(r)) => {
+            l.checked_mul(*r).map(Object::Number)
+        }
+        (BoundBinaryOperatorKind::Division, Object::Number(l), Object::Number(r)) => {
+            l.checked_div(*r).map(Object::Number)
+        }
+        (BoundBinaryOperatorKind::LogicalAnd, Object::Boolean(l), Object::Boolean(r)) => {
+            Some(Object::Boolean(*l && *r))
+        }
+        (BoundBinaryOperatorKind::LogicalOr, Object::Boolean(l), Object::Boolean(r)) => {
+            Some(Object::Boolean(*l || *r))
+        }
+        (BoundBinaryOperatorKind::Equality, Object::Number(l), Object::Number(r)) => {
+            Some(Object::Boolean(l == r))
+        }
+        (BoundBinaryOperatorKind::Equality, Object::Boolean(l), Object::Boolean(r)) => {
+            Some(Object::Boolean(l == r))
+        }
+        (BoundBinaryOperatorKind::Inequality, Object::Number(l), Object::Number(r)) => {
+            Some(Object::Boolean(l != r))
+        }
+        (BoundBinaryOperatorKind::Inequality, Object::Boolean(l), Object::Boolean(r)) => {
+            Some(Object::Boolean(l != r))
+        }
+        (BoundBinaryOperatorKind::LessOrEquals, Object::Number(l), Object::Number(r)) => {
+            Some(Object::Boolean(l <= r))
+        }
+        _ => None,
+    }
+}
+
+fn fold_unary(kind: BoundUnaryOperatorKind, operand: &Object) -> Option<Object> {
+    match (kind, operand) {
+        (BoundUnaryOperatorKind::Identity, Object::Number(v)) => Some(Object::Number(*v)),
+        (BoundUnaryOperatorKind::Negation, Object::Number(v)) => v.checked_neg().map(Object::Number),
+        (BoundUnaryOperatorKind::LogicalNegation, Object::Boolean(v)) => {
+            Some(Object::Boolean(!v))
+        }
+        _ => None,
+    }
+}
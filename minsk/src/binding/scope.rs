@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::diagnostic::Diagnostic;
+use crate::text::VariableSymbol;
+
+use super::BoundStatement;
+use super::FunctionSymbol;
+
+pub(crate) struct BoundScope {
+    pub(crate) parent: Option<Box<BoundScope>>,
+    variables: HashMap<String, VariableSymbol>,
+    functions: HashMap<String, FunctionSymbol>,
+}
+
+impl BoundScope {
+    pub(crate) fn new(parent: Option<Box<BoundScope>>) -> Self {
+        Self {
+            parent,
+            variables: HashMap::new(),
+            functions: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn try_declare(&mut self, variable: VariableSymbol) -> bool {
+        if self.variables.contains_key(&variable.name) {
+            return false;
+        }
+        self.variables.insert(variable.name.clone(), variable);
+        true
+    }
+
+    pub(crate) fn try_lookup(&self, name: &str) -> Option<&VariableSymbol> {
+        if let Some(variable) = self.variables.get(name) {
+            return Some(variable);
+        }
+        self.parent.as_ref().and_then(|p| p.try_lookup(name))
+    }
+
+    pub(crate) fn get_declared_variables(&self) -> Vec<&VariableSymbol> {
+        self.variables.values().collect()
+    }
+
+    pub(crate) fn try_declare_function(&mut self, function: FunctionSymbol) -> bool {
+        if self.functions.contains_key(&function.name) {
+            return false;
+        }
+        self.functions.insert(function.name.clone(), function);
+        true
+    }
+
+    pub(crate) fn try_lookup_function(&self, name: &str) -> Option<&FunctionSymbol> {
+        if let Some(function) = self.functions.get(name) {
+            return Some(function);
+        }
+        self.parent.as_ref().and_then(|p| p.try_lookup_function(name))
+    }
+
+    pub(crate) fn get_declared_functions(&self) -> Vec<&FunctionSymbol> {
+        self.functions.values().collect()
+    }
+}
+
+pub(crate) struct BoundGlobalScope {
+    pub(crate) previous: Option<Box<BoundGlobalScope>>,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    pub(crate) variables: Vec<VariableSymbol>,
+    pub(crate) functions: Vec<FunctionSymbol>,
+    pub(crate) statement: BoundStatement,
+}
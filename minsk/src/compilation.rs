@@ -0,0 +1,31 @@
+use crate::binding::scope::BoundGlobalScope;
+use crate::binding::Binder;
+use crate::diagnostic::Diagnostic;
+use crate::syntax::CompilationUnitSyntaxRef;
+
+/// One submission's worth of binding, optionally chained onto everything
+/// bound before it so a REPL-style session can keep declaring variables
+/// and functions across submissions.
+pub struct Compilation {
+    global_scope: BoundGlobalScope,
+}
+
+impl Compilation {
+    pub fn new(syntax: CompilationUnitSyntaxRef) -> Self {
+        Self {
+            global_scope: Binder::bind_global_scope(None, syntax),
+        }
+    }
+
+    /// Binds `syntax` against everything already declared in `self`,
+    /// without re-binding any of the previous submissions.
+    pub fn continue_with(self, syntax: CompilationUnitSyntaxRef) -> Compilation {
+        Self {
+            global_scope: Binder::bind_global_scope(Some(self.global_scope), syntax),
+        }
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.global_scope.diagnostics
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::vec;
+
+use crate::plumbing::ObjectKind;
+use crate::text::TextSpan;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Diagnostic {
+    pub(crate) span: TextSpan,
+    pub(crate) message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DiagnosticBag {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl DiagnosticBag {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn report(&mut self, span: TextSpan, message: String) {
+        self.diagnostics.push(Diagnostic { span, message });
+    }
+
+    pub(crate) fn report_undefined_name(&mut self, span: TextSpan, name: &str) {
+        self.report(span, format!("Variable or function '{name}' doesn't exist."));
+    }
+
+    pub(crate) fn report_undefined_binary_operator(
+        &mut self,
+        span: TextSpan,
+        operator_text: String,
+        left_type: ObjectKind,
+        right_type: ObjectKind,
+    ) {
+        self.report(
+            span,
+            format!(
+                "Binary operator '{operator_text}' is not defined for types {left_type:?} and {right_type:?}."
+            ),
+        );
+    }
+
+    pub(crate) fn report_undefined_unary_operator(
+        &mut self,
+        span: TextSpan,
+        operator_text: String,
+        operand_type: ObjectKind,
+    ) {
+        self.report(
+            span,
+            format!("Unary operator '{operator_text}' is not defined for type {operand_type:?}."),
+        );
+    }
+
+    pub(crate) fn report_cannot_assign(&mut self, span: TextSpan, name: &str) {
+        self.report(
+            span,
+            format!("Variable '{name}' is read-only and cannot be assigned to."),
+        );
+    }
+
+    pub(crate) fn report_cannot_convert(
+        &mut self,
+        span: TextSpan,
+        from_type: ObjectKind,
+        to_type: ObjectKind,
+    ) {
+        self.report(
+            span,
+            format!("Cannot convert type {from_type:?} to {to_type:?}."),
+        );
+    }
+
+    pub(crate) fn report_variable_already_declared(&mut self, span: TextSpan, name: &str) {
+        self.report(span, format!("Variable '{name}' is already declared."));
+    }
+
+    pub(crate) fn report_function_already_declared(&mut self, span: TextSpan, name: &str) {
+        self.report(span, format!("Function '{name}' is already declared."));
+    }
+
+    pub(crate) fn report_undefined_type(&mut self, span: TextSpan, name: &str) {
+        self.report(span, format!("Type '{name}' doesn't exist."));
+    }
+
+    pub(crate) fn report_wrong_argument_count(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        expected_count: usize,
+        actual_count: usize,
+    ) {
+        self.report(
+            span,
+            format!(
+                "Function '{name}' requires {expected_count} argument(s) but was given {actual_count}."
+            ),
+        );
+    }
+
+    pub(crate) fn report_invalid_return_expression(&mut self, span: TextSpan) {
+        self.report(
+            span,
+            "Since the function does not return a value, the 'return' keyword cannot be \
+             followed by an expression."
+                .to_string(),
+        );
+    }
+
+    pub(crate) fn report_missing_return_expression(
+        &mut self,
+        span: TextSpan,
+        return_type: ObjectKind,
+    ) {
+        self.report(span, format!("An expression of type {return_type:?} is expected."));
+    }
+}
+
+impl IntoIterator for DiagnosticBag {
+    type Item = Diagnostic;
+    type IntoIter = vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.into_iter()
+    }
+}
@@ -8,6 +8,11 @@ pub enum StatementSyntax {
     Block(BlockStatementSyntax),
     Expression(ExpressionStatementSyntax),
     VariableDeclaration(VariableDeclarationStatementSyntax),
+    If(IfStatementSyntax),
+    While(WhileStatementSyntax),
+    For(ForStatementSyntax),
+    FunctionDeclaration(FunctionDeclarationSyntax),
+    Return(ReturnStatementSyntax),
 }
 
 impl StatementSyntax {
@@ -16,6 +21,11 @@ impl StatementSyntax {
             StatementSyntax::Block(s) => StatementSyntaxRef::Block(s),
             StatementSyntax::Expression(s) => StatementSyntaxRef::Expression(s),
             StatementSyntax::VariableDeclaration(s) => StatementSyntaxRef::VariableDeclaration(s),
+            StatementSyntax::If(s) => StatementSyntaxRef::If(s),
+            StatementSyntax::While(s) => StatementSyntaxRef::While(s),
+            StatementSyntax::For(s) => StatementSyntaxRef::For(s),
+            StatementSyntax::FunctionDeclaration(s) => StatementSyntaxRef::FunctionDeclaration(s),
+            StatementSyntax::Return(s) => StatementSyntaxRef::Return(s),
         }
     }
 }
@@ -25,6 +35,11 @@ pub enum StatementSyntaxRef<'a> {
     Block(&'a BlockStatementSyntax),
     Expression(&'a ExpressionStatementSyntax),
     VariableDeclaration(&'a VariableDeclarationStatementSyntax),
+    If(&'a IfStatementSyntax),
+    While(&'a WhileStatementSyntax),
+    For(&'a ForStatementSyntax),
+    FunctionDeclaration(&'a FunctionDeclarationSyntax),
+    Return(&'a ReturnStatementSyntax),
 }
 
 impl<'a> StatementSyntaxRef<'a> {
@@ -33,6 +48,11 @@ impl<'a> StatementSyntaxRef<'a> {
             StatementSyntaxRef::Block(_) => SyntaxKind::BlockStatement,
             StatementSyntaxRef::Expression(_) => SyntaxKind::ExpressionStatement,
             StatementSyntaxRef::VariableDeclaration(_) => SyntaxKind::VariableDeclarationStatement,
+            StatementSyntaxRef::If(_) => SyntaxKind::IfStatement,
+            StatementSyntaxRef::While(_) => SyntaxKind::WhileStatement,
+            StatementSyntaxRef::For(_) => SyntaxKind::ForStatement,
+            StatementSyntaxRef::FunctionDeclaration(_) => SyntaxKind::FunctionDeclaration,
+            StatementSyntaxRef::Return(_) => SyntaxKind::ReturnStatement,
         }
     }
 
@@ -53,12 +73,75 @@ impl<'a> StatementSyntaxRef<'a> {
             StatementSyntaxRef::Expression(s) => {
                 vec![SyntaxNodeRef::Expression(s.expression.create_ref())]
             }
-            StatementSyntaxRef::VariableDeclaration(s) => vec![
-                SyntaxNodeRef::Token(&s.keyword),
+            StatementSyntaxRef::VariableDeclaration(s) => {
+                let mut result = vec![
+                    SyntaxNodeRef::Token(&s.keyword),
+                    SyntaxNodeRef::Token(&s.identifier),
+                ];
+                if let Some(type_clause) = &s.type_clause {
+                    result.push(SyntaxNodeRef::Token(&type_clause.colon_token));
+                    result.push(SyntaxNodeRef::Token(&type_clause.identifier));
+                }
+                result.push(SyntaxNodeRef::Token(&s.equals_token));
+                result.push(SyntaxNodeRef::Expression(s.initializer.create_ref()));
+                result
+            }
+            StatementSyntaxRef::If(s) => {
+                let mut result = vec![
+                    SyntaxNodeRef::Token(&s.if_keyword),
+                    SyntaxNodeRef::Expression(s.condition.create_ref()),
+                    SyntaxNodeRef::Statement(s.then_statement.create_ref()),
+                ];
+                if let Some(else_clause) = &s.else_clause {
+                    result.push(SyntaxNodeRef::Token(&else_clause.else_keyword));
+                    result.push(SyntaxNodeRef::Statement(
+                        else_clause.else_statement.create_ref(),
+                    ));
+                }
+                result
+            }
+            StatementSyntaxRef::While(s) => vec![
+                SyntaxNodeRef::Token(&s.while_keyword),
+                SyntaxNodeRef::Expression(s.condition.create_ref()),
+                SyntaxNodeRef::Statement(s.body.create_ref()),
+            ],
+            StatementSyntaxRef::For(s) => vec![
+                SyntaxNodeRef::Token(&s.for_keyword),
                 SyntaxNodeRef::Token(&s.identifier),
                 SyntaxNodeRef::Token(&s.equals_token),
-                SyntaxNodeRef::Expression(s.initializer.create_ref()),
+                SyntaxNodeRef::Expression(s.lower_bound.create_ref()),
+                SyntaxNodeRef::Token(&s.to_keyword),
+                SyntaxNodeRef::Expression(s.upper_bound.create_ref()),
+                SyntaxNodeRef::Statement(s.body.create_ref()),
             ],
+            StatementSyntaxRef::FunctionDeclaration(s) => {
+                let mut result = vec![
+                    SyntaxNodeRef::Token(&s.function_keyword),
+                    SyntaxNodeRef::Token(&s.identifier),
+                    SyntaxNodeRef::Token(&s.open_parenthesis_token),
+                ];
+                result.append(
+                    &mut s
+                        .parameters
+                        .iter()
+                        .map(|p| SyntaxNodeRef::Token(&p.identifier))
+                        .collect(),
+                );
+                result.push(SyntaxNodeRef::Token(&s.close_parenthesis_token));
+                if let Some(type_clause) = &s.type_clause {
+                    result.push(SyntaxNodeRef::Token(&type_clause.colon_token));
+                    result.push(SyntaxNodeRef::Token(&type_clause.identifier));
+                }
+                result.push(SyntaxNodeRef::Statement(StatementSyntaxRef::Block(&s.body)));
+                result
+            }
+            StatementSyntaxRef::Return(s) => {
+                let mut result = vec![SyntaxNodeRef::Token(&s.return_keyword)];
+                if let Some(expression) = &s.expression {
+                    result.push(SyntaxNodeRef::Expression(expression.create_ref()));
+                }
+                result
+            }
         }
     }
 }
@@ -79,6 +162,68 @@ pub struct ExpressionStatementSyntax {
 pub struct VariableDeclarationStatementSyntax {
     pub keyword: SyntaxToken,
     pub identifier: SyntaxToken,
+    pub type_clause: Option<TypeClauseSyntax>,
     pub equals_token: SyntaxToken,
     pub initializer: ExpressionSyntax,
 }
+
+#[derive(Debug, Clone)]
+pub struct IfStatementSyntax {
+    pub if_keyword: SyntaxToken,
+    pub condition: ExpressionSyntax,
+    pub then_statement: Box<StatementSyntax>,
+    pub else_clause: Option<ElseClauseSyntax>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ElseClauseSyntax {
+    pub else_keyword: SyntaxToken,
+    pub else_statement: Box<StatementSyntax>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WhileStatementSyntax {
+    pub while_keyword: SyntaxToken,
+    pub condition: ExpressionSyntax,
+    pub body: Box<StatementSyntax>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForStatementSyntax {
+    pub for_keyword: SyntaxToken,
+    pub identifier: SyntaxToken,
+    pub equals_token: SyntaxToken,
+    pub lower_bound: ExpressionSyntax,
+    pub to_keyword: SyntaxToken,
+    pub upper_bound: ExpressionSyntax,
+    pub body: Box<StatementSyntax>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParameterSyntax {
+    pub identifier: SyntaxToken,
+    pub type_clause: TypeClauseSyntax,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeClauseSyntax {
+    pub colon_token: SyntaxToken,
+    pub identifier: SyntaxToken,
+}
+
+#[derive(Debug, Clone)]
+pub struct FunctionDeclarationSyntax {
+    pub function_keyword: SyntaxToken,
+    pub identifier: SyntaxToken,
+    pub open_parenthesis_token: SyntaxToken,
+    pub parameters: Vec<ParameterSyntax>,
+    pub close_parenthesis_token: SyntaxToken,
+    pub type_clause: Option<TypeClauseSyntax>,
+    pub body: BlockStatementSyntax,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReturnStatementSyntax {
+    pub return_keyword: SyntaxToken,
+    pub expression: Option<ExpressionSyntax>,
+}